@@ -1,21 +1,38 @@
 use cc::{CryptoError, Hash, PrivateKey, PublicKey, Signature};
-use secp256k1::{Message, Secp256k1, SignOnly, ThirtyTwoByteHash, VerifyOnly};
+use secp256k1::recovery::{RecoverableSignature, RecoveryId};
+use secp256k1::{All, Message, Secp256k1, ThirtyTwoByteHash};
 
 use std::convert::TryFrom;
 
+lazy_static::lazy_static! {
+    // A single context shared by every operation. Building a context computes
+    // the precomputation tables (~10ms) whereas the crypto ops themselves take
+    // ~50µs, so constructing one per call would dominate the cost.
+    static ref SECP256K1: Secp256k1<All> = Secp256k1::new();
+}
+
 pub struct Secp256k1PrivateKey {
     secret_key: secp256k1::SecretKey,
-    engine: Secp256k1<SignOnly>,
 }
 
 pub struct Secp256k1PublicKey {
     pub_key: secp256k1::PublicKey,
-    engine: Secp256k1<VerifyOnly>,
 }
 
 pub struct Secp256k1Signature {
     sig: secp256k1::Signature,
-    engine: Secp256k1<VerifyOnly>,
+}
+
+pub struct Secp256k1RecoverableSignature {
+    sig: RecoverableSignature,
+}
+
+pub struct Secp256k1SchnorrSignature {
+    sig: secp256k1::schnorrsig::Signature,
+}
+
+pub struct Secp256k1XOnlyPublicKey {
+    pub_key: secp256k1::schnorrsig::PublicKey,
 }
 
 #[derive(Debug, PartialEq)]
@@ -32,9 +49,8 @@ impl TryFrom<&[u8]> for Secp256k1PrivateKey {
 
     fn try_from(bytes: &[u8]) -> Result<Secp256k1PrivateKey, Self::Error> {
         let secret_key = secp256k1::SecretKey::from_slice(bytes).map_err(Secp256k1Error)?;
-        let engine = Secp256k1::signing_only();
 
-        Ok(Secp256k1PrivateKey { secret_key, engine })
+        Ok(Secp256k1PrivateKey { secret_key })
     }
 }
 
@@ -44,17 +60,15 @@ impl PrivateKey<32> for Secp256k1PrivateKey {
 
     fn sign_message(&self, msg: &Hash) -> Self::Signature {
         let msg = Message::from(HashedMessage(msg));
-        let sig = self.engine.sign(&msg, &self.secret_key);
-        let engine = Secp256k1::verification_only();
+        let sig = SECP256K1.sign(&msg, &self.secret_key);
 
-        Secp256k1Signature { sig, engine }
+        Secp256k1Signature { sig }
     }
 
     fn pub_key(&self) -> Self::PublicKey {
-        let pub_key = secp256k1::PublicKey::from_secret_key(&self.engine, &self.secret_key);
-        let engine = Secp256k1::verification_only();
+        let pub_key = secp256k1::PublicKey::from_secret_key(&SECP256K1, &self.secret_key);
 
-        Secp256k1PublicKey { pub_key, engine }
+        Secp256k1PublicKey { pub_key }
     }
 
     fn to_bytes(&self) -> [u8; 32] {
@@ -65,6 +79,104 @@ impl PrivateKey<32> for Secp256k1PrivateKey {
     }
 }
 
+impl Secp256k1PrivateKey {
+    pub fn sign_recoverable(&self, msg: &Hash) -> Secp256k1RecoverableSignature {
+        let msg = Message::from(HashedMessage(msg));
+        let sig = SECP256K1.sign_recoverable(&msg, &self.secret_key);
+
+        Secp256k1RecoverableSignature { sig }
+    }
+}
+
+impl Secp256k1PrivateKey {
+    pub fn sign_schnorr(&self, msg: &Hash) -> Secp256k1SchnorrSignature {
+        let msg = Message::from(HashedMessage(msg));
+        let key_pair = secp256k1::schnorrsig::KeyPair::from_secret_key(&SECP256K1, self.secret_key);
+        let sig = SECP256K1.schnorrsig_sign_no_aux_rand(&msg, &key_pair);
+
+        Secp256k1SchnorrSignature { sig }
+    }
+
+    pub fn x_only_pub_key(&self) -> Secp256k1XOnlyPublicKey {
+        let key_pair = secp256k1::schnorrsig::KeyPair::from_secret_key(&SECP256K1, self.secret_key);
+        let pub_key = secp256k1::schnorrsig::PublicKey::from_keypair(&SECP256K1, &key_pair);
+
+        Secp256k1XOnlyPublicKey { pub_key }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Secp256k1PrivateKey {
+    pub fn generate<R: rand::Rng + ?Sized>(rng: &mut R) -> Secp256k1PrivateKey {
+        // Draw 32 bytes and retry until they form a valid scalar, i.e. non-zero
+        // and below the curve order. `SecretKey::from_slice` performs that check
+        // via `secp256k1_ec_seckey_verify`, so a rejection just means redraw.
+        loop {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+
+            if let Ok(secret_key) = secp256k1::SecretKey::from_slice(&bytes) {
+                return Secp256k1PrivateKey { secret_key };
+            }
+        }
+    }
+}
+
+//
+// Tweak Impl
+//
+
+impl Secp256k1PrivateKey {
+    pub fn add_tweak(&self, tweak: &[u8; 32]) -> Result<Secp256k1PrivateKey, CryptoError> {
+        let mut secret_key = self.secret_key;
+        secret_key.add_assign(&tweak[..]).map_err(Secp256k1Error)?;
+
+        Ok(Secp256k1PrivateKey { secret_key })
+    }
+
+    pub fn mul_tweak(&self, tweak: &[u8; 32]) -> Result<Secp256k1PrivateKey, CryptoError> {
+        let mut secret_key = self.secret_key;
+        secret_key.mul_assign(&tweak[..]).map_err(Secp256k1Error)?;
+
+        Ok(Secp256k1PrivateKey { secret_key })
+    }
+}
+
+impl Secp256k1PublicKey {
+    pub fn add_tweak(&self, tweak: &[u8; 32]) -> Result<Secp256k1PublicKey, CryptoError> {
+        let mut pub_key = self.pub_key;
+        pub_key
+            .add_exp_assign(&SECP256K1, &tweak[..])
+            .map_err(Secp256k1Error)?;
+
+        Ok(Secp256k1PublicKey { pub_key })
+    }
+
+    pub fn mul_tweak(&self, tweak: &[u8; 32]) -> Result<Secp256k1PublicKey, CryptoError> {
+        let mut pub_key = self.pub_key;
+        pub_key
+            .mul_assign(&SECP256K1, &tweak[..])
+            .map_err(Secp256k1Error)?;
+
+        Ok(Secp256k1PublicKey { pub_key })
+    }
+}
+
+//
+// ECDH Impl
+//
+
+impl Secp256k1PrivateKey {
+    pub fn ecdh(&self, peer: &Secp256k1PublicKey) -> [u8; 32] {
+        let secret = secp256k1::ecdh::SharedSecret::new(&peer.pub_key, &self.secret_key);
+
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&secret[..]);
+
+        bytes
+    }
+}
+
 //
 // PublicKey Impl
 //
@@ -74,9 +186,8 @@ impl TryFrom<&[u8]> for Secp256k1PublicKey {
 
     fn try_from(bytes: &[u8]) -> Result<Secp256k1PublicKey, Self::Error> {
         let pub_key = secp256k1::PublicKey::from_slice(bytes).map_err(Secp256k1Error)?;
-        let engine = Secp256k1::verification_only();
 
-        Ok(Secp256k1PublicKey { pub_key, engine })
+        Ok(Secp256k1PublicKey { pub_key })
     }
 }
 
@@ -86,7 +197,7 @@ impl PublicKey<33> for Secp256k1PublicKey {
     fn verify_signature(&self, msg: &Hash, sig: &Self::Signature) -> Result<(), CryptoError> {
         let msg = Message::from(HashedMessage(msg));
 
-        self.engine
+        SECP256K1
             .verify(&msg, &sig.sig, &self.pub_key)
             .map_err(Secp256k1Error)?;
 
@@ -107,9 +218,8 @@ impl TryFrom<&[u8]> for Secp256k1Signature {
 
     fn try_from(bytes: &[u8]) -> Result<Secp256k1Signature, Self::Error> {
         let sig = secp256k1::Signature::from_compact(bytes).map_err(Secp256k1Error)?;
-        let engine = Secp256k1::verification_only();
 
-        Ok(Secp256k1Signature { sig, engine })
+        Ok(Secp256k1Signature { sig })
     }
 }
 
@@ -119,7 +229,7 @@ impl Signature<64> for Secp256k1Signature {
     fn verify(&self, msg: &Hash, pub_key: &Self::PublicKey) -> Result<(), CryptoError> {
         let msg = Message::from(HashedMessage(msg));
 
-        self.engine
+        SECP256K1
             .verify(&msg, &self.sig, &pub_key.pub_key)
             .map_err(Secp256k1Error)?;
 
@@ -131,6 +241,133 @@ impl Signature<64> for Secp256k1Signature {
     }
 }
 
+//
+// RecoverableSignature Impl
+//
+
+impl TryFrom<&[u8]> for Secp256k1RecoverableSignature {
+    type Error = CryptoError;
+
+    fn try_from(bytes: &[u8]) -> Result<Secp256k1RecoverableSignature, Self::Error> {
+        if bytes.len() != 65 {
+            return Err(CryptoError::InvalidLength);
+        }
+
+        let recovery_id = RecoveryId::from_i32(i32::from(bytes[0])).map_err(Secp256k1Error)?;
+        let sig = RecoverableSignature::from_compact(&bytes[1..], recovery_id).map_err(Secp256k1Error)?;
+
+        Ok(Secp256k1RecoverableSignature { sig })
+    }
+}
+
+impl Secp256k1RecoverableSignature {
+    pub fn recover(&self, msg: &Hash) -> Result<Secp256k1PublicKey, CryptoError> {
+        let msg = Message::from(HashedMessage(msg));
+        let pub_key = SECP256K1.recover(&msg, &self.sig).map_err(Secp256k1Error)?;
+
+        Ok(Secp256k1PublicKey { pub_key })
+    }
+
+    pub fn to_bytes(&self) -> [u8; 65] {
+        let (recovery_id, data) = self.sig.serialize_compact();
+
+        let mut bytes = [0u8; 65];
+        bytes[0] = recovery_id.to_i32() as u8;
+        bytes[1..].copy_from_slice(&data);
+
+        bytes
+    }
+
+    pub fn from_rsv(r: &[u8; 32], s: &[u8; 32], v: u8) -> Result<Secp256k1RecoverableSignature, CryptoError> {
+        // `v` carries the recovery id, which Ethereum/Parity tooling usually
+        // stores with the +27 offset; strip it back to the raw 0/1 id.
+        let recovery_id = if v >= 27 { v - 27 } else { v };
+        let recovery_id = RecoveryId::from_i32(i32::from(recovery_id)).map_err(Secp256k1Error)?;
+
+        let mut data = [0u8; 64];
+        data[..32].copy_from_slice(&r[..]);
+        data[32..].copy_from_slice(&s[..]);
+
+        let sig = RecoverableSignature::from_compact(&data, recovery_id).map_err(Secp256k1Error)?;
+
+        Ok(Secp256k1RecoverableSignature { sig })
+    }
+
+    pub fn to_rsv(&self) -> ([u8; 32], [u8; 32], u8) {
+        let (recovery_id, data) = self.sig.serialize_compact();
+
+        let mut r = [0u8; 32];
+        let mut s = [0u8; 32];
+        r.copy_from_slice(&data[..32]);
+        s.copy_from_slice(&data[32..]);
+
+        (r, s, recovery_id.to_i32() as u8)
+    }
+}
+
+//
+// SchnorrSignature Impl
+//
+
+impl TryFrom<&[u8]> for Secp256k1SchnorrSignature {
+    type Error = CryptoError;
+
+    fn try_from(bytes: &[u8]) -> Result<Secp256k1SchnorrSignature, Self::Error> {
+        let sig = secp256k1::schnorrsig::Signature::from_slice(bytes).map_err(Secp256k1Error)?;
+
+        Ok(Secp256k1SchnorrSignature { sig })
+    }
+}
+
+impl Signature<64> for Secp256k1SchnorrSignature {
+    type PublicKey = Secp256k1XOnlyPublicKey;
+
+    fn verify(&self, msg: &Hash, pub_key: &Self::PublicKey) -> Result<(), CryptoError> {
+        pub_key.verify_schnorr(msg, self)
+    }
+
+    fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(self.sig.as_ref());
+
+        bytes
+    }
+}
+
+//
+// XOnlyPublicKey Impl
+//
+
+impl TryFrom<&[u8]> for Secp256k1XOnlyPublicKey {
+    type Error = CryptoError;
+
+    fn try_from(bytes: &[u8]) -> Result<Secp256k1XOnlyPublicKey, Self::Error> {
+        let pub_key = secp256k1::schnorrsig::PublicKey::from_slice(bytes).map_err(Secp256k1Error)?;
+
+        Ok(Secp256k1XOnlyPublicKey { pub_key })
+    }
+}
+
+impl Secp256k1XOnlyPublicKey {
+    pub fn verify_schnorr(
+        &self,
+        msg: &Hash,
+        sig: &Secp256k1SchnorrSignature,
+    ) -> Result<(), CryptoError> {
+        let msg = Message::from(HashedMessage(msg));
+
+        SECP256K1
+            .schnorrsig_verify(&sig.sig, &msg, &self.pub_key)
+            .map_err(Secp256k1Error)?;
+
+        Ok(())
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.pub_key.serialize()
+    }
+}
+
 //
 // Error Impl
 //
@@ -145,8 +382,8 @@ impl From<Secp256k1Error> for CryptoError {
             Error::InvalidPublicKey => CryptoError::InvalidPublicKey,
             Error::InvalidSignature => CryptoError::InvalidSignature,
             Error::InvalidSecretKey => CryptoError::InvalidPrivateKey,
-            Error::InvalidRecoveryId => CryptoError::InvalidSignature,
-            Error::InvalidTweak => CryptoError::Other("secp256k1: bad tweak"),
+            Error::InvalidRecoveryId => CryptoError::InvalidRecoveryId,
+            Error::InvalidTweak => CryptoError::InvalidTweak,
             Error::NotEnoughMemory => CryptoError::Other("secp256k1: not enough memory"),
         }
     }